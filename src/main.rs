@@ -1,12 +1,14 @@
 use clap::{builder::PossibleValue, Parser, ValueEnum};
 use log::debug;
-use rand::{seq::IteratorRandom, thread_rng};
+use rand::{thread_rng, Rng};
 use serde::Deserialize;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
     fs::{self, File},
+    hash::{Hash, Hasher},
     io::BufReader,
     path::PathBuf,
+    time::Duration,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
@@ -130,37 +132,255 @@ impl ValueEnum for EthnicityFilter {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Unit {
+    Gram,
+    Kilogram,
+    Milliliter,
+    Liter,
+    Teaspoon,
+    Tablespoon,
+    Cup,
+    Ounce,
+    Pound,
+}
+
+impl Unit {
+    /// Match a lowercased token against the known unit table.
+    fn from_token(token: &str) -> Option<Self> {
+        Some(match token {
+            "g" | "gram" | "grams" => Self::Gram,
+            "kg" | "kilogram" | "kilograms" => Self::Kilogram,
+            "ml" | "milliliter" | "milliliters" => Self::Milliliter,
+            "l" | "liter" | "liters" => Self::Liter,
+            "tsp" | "teaspoon" | "teaspoons" => Self::Teaspoon,
+            "tbsp" | "tablespoon" | "tablespoons" => Self::Tablespoon,
+            "cup" | "cups" => Self::Cup,
+            "oz" | "ounce" | "ounces" => Self::Ounce,
+            "lb" | "lbs" | "pound" | "pounds" => Self::Pound,
+            _ => return None,
+        })
+    }
+
+    /// The short form used when printing a grocery list.
+    fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Gram => "g",
+            Self::Kilogram => "kg",
+            Self::Milliliter => "ml",
+            Self::Liter => "l",
+            Self::Teaspoon => "tsp",
+            Self::Tablespoon => "tbsp",
+            Self::Cup => "cup",
+            Self::Ounce => "oz",
+            Self::Pound => "lb",
+        }
+    }
+}
+
+/// A single structured ingredient line, e.g. `"135g plain flour"`.
+///
+/// Recipe YAML stores ingredients as free-form strings, so this type
+/// deserializes straight from a string via [`Ingredient::parse`].
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(from = "String")]
+struct Ingredient {
+    quantity: Option<f64>,
+    unit: Option<Unit>,
+    name: String,
+}
+
+impl Ingredient {
+    /// Parse a free-form ingredient line into a quantity, an optional unit,
+    /// and the remaining name. Lines with no recognizable leading quantity
+    /// become count-less pantry items (`quantity` and `unit` are `None`).
+    fn parse(line: &str) -> Self {
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        if tokens.is_empty() {
+            return Self {
+                quantity: None,
+                unit: None,
+                name: String::new(),
+            };
+        }
+
+        let (num_part, glued) = split_numeric_prefix(tokens[0]);
+        let Some(quantity) = parse_quantity(num_part) else {
+            return Self {
+                quantity: None,
+                unit: None,
+                name: tokens.join(" "),
+            };
+        };
+
+        if !glued.is_empty() {
+            // The number and unit were glued together, e.g. "135g".
+            return match Unit::from_token(&glued.to_lowercase()) {
+                Some(unit) => Self {
+                    quantity: Some(quantity),
+                    unit: Some(unit),
+                    name: tokens[1..].join(" "),
+                },
+                // Not a recognizable unit; treat the whole line as a name.
+                None => Self {
+                    quantity: None,
+                    unit: None,
+                    name: tokens.join(" "),
+                },
+            };
+        }
+
+        match tokens.get(1).and_then(|t| Unit::from_token(&t.to_lowercase())) {
+            Some(unit) => Self {
+                quantity: Some(quantity),
+                unit: Some(unit),
+                name: tokens[2..].join(" "),
+            },
+            None => Self {
+                quantity: Some(quantity),
+                unit: None,
+                name: tokens[1..].join(" "),
+            },
+        }
+    }
+}
+
+impl From<String> for Ingredient {
+    fn from(line: String) -> Self {
+        Self::parse(&line)
+    }
+}
+
+/// Split off the leading run of digits, `.` and `/` from a token, returning
+/// the numeric prefix and whatever follows (e.g. `"135g"` -> `("135", "g")`).
+fn split_numeric_prefix(token: &str) -> (&str, &str) {
+    let end = token
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '/'))
+        .unwrap_or(token.len());
+    token.split_at(end)
+}
+
+/// Parse an integer, decimal, or simple fraction (`"1/2"`) into a quantity.
+fn parse_quantity(token: &str) -> Option<f64> {
+    if let Some((num, den)) = token.split_once('/') {
+        let num = num.parse::<f64>().ok()?;
+        let den = den.parse::<f64>().ok()?;
+        if den == 0.0 {
+            return None;
+        }
+        Some(num / den)
+    } else {
+        token.parse::<f64>().ok()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lang {
+    #[default]
+    Eng,
+    Fra,
+    Spa,
+}
+
+impl std::fmt::Display for Lang {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl ValueEnum for Lang {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Eng, Self::Fra, Self::Spa]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(match self {
+            Self::Eng => PossibleValue::new("eng").help("Render recipes in English."),
+            Self::Fra => PossibleValue::new("fra").help("Render recipes in French."),
+            Self::Spa => PossibleValue::new("spa").help("Render recipes in Spanish."),
+        })
+    }
+}
+
+/// A field that carries a required English default plus optional per-language
+/// translations, deserialized from a map such as `{ eng: "...", fra: "..." }`.
+#[derive(Debug, PartialEq, Deserialize)]
+struct Translated<T> {
+    eng: T,
+    #[serde(flatten)]
+    translations: HashMap<Lang, T>,
+}
+
+impl<T> Translated<T> {
+    /// Resolve to the requested language, falling back to the English default.
+    fn get(&self, lang: Lang) -> &T {
+        match lang {
+            Lang::Eng => &self.eng,
+            other => self.translations.get(&other).unwrap_or(&self.eng),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 struct Recipe {
-    name: String,
+    name: Translated<String>,
     seasons: Vec<SeasonFilter>,
     ethnicities: Vec<EthnicityFilter>,
-    ingredients: Vec<String>, // TODO - replace with an ingredient struct.
-    steps: Vec<String>,
+    ingredients: Translated<Vec<Ingredient>>,
+    steps: Translated<Vec<String>>,
+    /// Relative selection weight; absent means the default weight of 1.
+    #[serde(default)]
+    weight: Option<u64>,
+}
+
+impl Recipe {
+    /// The effective selection weight, defaulting to 1 when unspecified.
+    fn weight(&self) -> u64 {
+        self.weight.unwrap_or(1)
+    }
 }
 
 struct Recipes {
     inner: HashMap<PathBuf, Recipe>,
     season_filter: HashSet<SeasonFilter>,
     ethnicity_filter: HashSet<EthnicityFilter>,
+    include_ingredients: Vec<String>,
+    exclude_ingredients: Vec<String>,
+    pantry: HashSet<String>,
+    lang: Lang,
 }
 
 impl Recipes {
     pub fn from_args(args: &GetRandomRecipes) -> Self {
         let mut inner = HashMap::new();
 
-        for input_path_res in fs::read_dir(&args.recipes_dir).unwrap() {
-            let input_path = input_path_res.unwrap().path();
-            if input_path.ends_with(".yaml") || input_path.ends_with(".yml") {
-                let reader = BufReader::new(File::open(&input_path).unwrap());
-                inner.insert(input_path, serde_yaml::from_reader(reader).unwrap());
+        if let Some(recipes_dir) = &args.recipes_dir {
+            for input_path_res in fs::read_dir(recipes_dir).unwrap() {
+                let input_path = input_path_res.unwrap().path();
+                if is_yaml(&input_path) {
+                    let reader = BufReader::new(File::open(&input_path).unwrap());
+                    inner.insert(input_path, serde_yaml::from_reader(reader).unwrap());
+                }
             }
         }
 
+        for url in &args.recipe_url {
+            inner.insert(cache_path_for(url), load_remote_recipe(url, args.cache_ttl));
+        }
+
         Self {
             inner,
             season_filter: args.season.iter().cloned().collect::<HashSet<_>>(),
             ethnicity_filter: args.ethnicity.iter().cloned().collect::<HashSet<_>>(),
+            include_ingredients: lowercased(&args.include_ingredient),
+            exclude_ingredients: lowercased(&args.exclude_ingredient),
+            pantry: read_pantry(args.pantry.as_ref()),
+            lang: args.lang,
         }
     }
 
@@ -175,30 +395,301 @@ impl Recipes {
                 .seasons
                 .iter()
                 .any(|e| self.season_filter.contains(e));
-        passes_ethnicity_filter && passes_season_filter
+
+        let names = recipe
+            .ingredients
+            .get(self.lang)
+            .iter()
+            .map(|i| i.name.to_lowercase())
+            .collect::<Vec<_>>();
+        let passes_include = self.include_ingredients.is_empty()
+            || self
+                .include_ingredients
+                .iter()
+                .any(|inc| names.iter().any(|n| n.contains(inc)));
+        let passes_exclude = self
+            .exclude_ingredients
+            .iter()
+            .all(|exc| !names.iter().any(|n| n.contains(exc)));
+
+        passes_ethnicity_filter && passes_season_filter && passes_include && passes_exclude
+    }
+
+    /// Count how many of a recipe's ingredients are already on hand.
+    fn pantry_score(&self, recipe: &Recipe) -> usize {
+        recipe
+            .ingredients
+            .get(self.lang)
+            .iter()
+            .filter(|i| {
+                let name = i.name.to_lowercase();
+                self.pantry
+                    .iter()
+                    .any(|p| name.contains(p) || p.contains(&name))
+            })
+            .count()
     }
 
-    pub fn randomly_select_recipes(&self, num_recipes: usize) -> Vec<PathBuf> {
-        let keys = self
+    /// The selection weight a recipe draws with, biasing its configured weight
+    /// by how many of its ingredients are already in the pantry. A zero-weight
+    /// recipe stays at zero so it is never selected, pantry or not.
+    fn effective_weight(&self, recipe: &Recipe) -> u64 {
+        recipe.weight() * (1 + self.pantry_score(recipe) as u64)
+    }
+
+    /// Select recipes with weighted random sampling. When a pantry is
+    /// configured its matches bias the draw toward recipes that reuse on-hand
+    /// ingredients, rather than replacing the randomizer with a fixed ranking.
+    pub fn select_recipes(&self, num_recipes: usize) -> Vec<PathBuf> {
+        let pool = self
             .inner
-            .keys()
-            .map(|p| (p, self.inner.get(p).unwrap()))
-            .filter(|(_p, r)| self.passes_filters(r))
-            .collect::<Vec<_>>();
+            .iter()
+            .filter(|(_p, r)| self.passes_filters(r) && r.weight() > 0)
+            .map(|(p, r)| (self.effective_weight(r), p))
+            .collect::<Vec<(u64, &PathBuf)>>();
+        self.weighted_draw(pool, num_recipes)
+    }
+
+    /// Draw up to `num_recipes` paths from a `(weight, path)` pool with weighted
+    /// sampling without replacement: draw against the running total, then drop
+    /// the chosen entry so it can't be picked again.
+    fn weighted_draw(&self, mut pool: Vec<(u64, &PathBuf)>, num_recipes: usize) -> Vec<PathBuf> {
         let mut rng = thread_rng();
 
-        let num_to_select = if keys.len() < num_recipes {
+        let num_to_select = if pool.len() < num_recipes {
             debug!("Number of recipes matching filter was less than requested number of recipes, returning all recipes available matching filters.");
-            keys.len()
+            pool.len()
         } else {
             num_recipes
         };
 
-        keys.iter()
-            .choose_multiple(&mut rng, num_to_select)
+        let mut selected = Vec::with_capacity(num_to_select);
+        while selected.len() < num_to_select {
+            let weights = pool.iter().map(|(w, _)| *w).collect::<Vec<_>>();
+            let (_weight, path) = pool.swap_remove(weighted_index(&weights, &mut rng));
+            selected.push(path.clone());
+        }
+        selected
+    }
+
+    /// Build an `num_days`-slot meal plan that greedily reuses ingredients
+    /// across its days. Each day scores the remaining
+    /// candidates by how many ingredient names they share with the recipes
+    /// already chosen, then draws among the top-scoring ones with the weighted
+    /// rng so produce bought for one meal gets used in a nearby one.
+    pub fn plan_days(&self, num_days: usize) -> Vec<PathBuf> {
+        // Zero-weight recipes are never selected (see chunk0-2); dropping them
+        // here keeps that invariant when `weighted_index` would otherwise fall
+        // back to a uniform pick among an all-zero-weight candidate set.
+        let mut pool = self
+            .inner
             .iter()
-            .map(|(p, _r)| (*p).clone())
-            .collect::<Vec<_>>()
+            .filter(|(_p, r)| self.passes_filters(r) && r.weight() > 0)
+            .map(|(p, r)| {
+                let names = r
+                    .ingredients
+                    .get(self.lang)
+                    .iter()
+                    .map(|i| i.name.trim().to_lowercase())
+                    .collect::<HashSet<_>>();
+                (r.weight(), p, names)
+            })
+            .collect::<Vec<(u64, &PathBuf, HashSet<String>)>>();
+        let mut rng = thread_rng();
+
+        let days = num_days.min(pool.len());
+        let mut chosen_names = HashSet::new();
+        let mut plan = Vec::with_capacity(days);
+        for _ in 0..days {
+            let best = pool
+                .iter()
+                .map(|(_w, _p, names)| names.intersection(&chosen_names).count())
+                .max()
+                .unwrap_or(0);
+            let top = pool
+                .iter()
+                .enumerate()
+                .filter(|(_, (_w, _p, names))| {
+                    names.intersection(&chosen_names).count() == best
+                })
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            let weights = top.iter().map(|&i| pool[i].0).collect::<Vec<_>>();
+            let picked = top[weighted_index(&weights, &mut rng)];
+            let (_weight, path, names) = pool.swap_remove(picked);
+            chosen_names.extend(names);
+            plan.push(path.clone());
+        }
+        plan
+    }
+
+    /// Build a consolidated grocery list for the selected recipes. Quantities
+    /// of compatible units (same normalized name and unit) are summed;
+    /// incompatible-unit items for the same name are listed separately, and
+    /// count-less ingredients are listed as plain pantry items.
+    pub fn grocery_list(&self, paths: &[PathBuf]) -> Vec<String> {
+        let mut totals: HashMap<(String, Option<Unit>), (f64, String)> = HashMap::new();
+        let mut pantry: HashMap<String, String> = HashMap::new();
+
+        for path in paths {
+            let Some(recipe) = self.inner.get(path) else {
+                continue;
+            };
+            for ingredient in recipe.ingredients.get(self.lang) {
+                let key = ingredient.name.trim().to_lowercase();
+                match ingredient.quantity {
+                    Some(quantity) => {
+                        let entry = totals
+                            .entry((key, ingredient.unit))
+                            .or_insert((0.0, ingredient.name.clone()));
+                        entry.0 += quantity;
+                    }
+                    None => {
+                        pantry.entry(key).or_insert_with(|| ingredient.name.clone());
+                    }
+                }
+            }
+        }
+
+        let mut lines = totals
+            .into_iter()
+            .map(|((_key, unit), (quantity, name))| format_grocery_item(quantity, unit, &name))
+            .chain(pantry.into_values())
+            .collect::<Vec<_>>();
+        lines.sort();
+        lines
+    }
+}
+
+/// Render a summed quantity, optional unit, and name into a grocery line.
+fn format_grocery_item(quantity: f64, unit: Option<Unit>, name: &str) -> String {
+    let quantity = format_quantity(quantity);
+    match unit {
+        Some(unit) => format!("{quantity}{} {name}", unit.abbreviation()),
+        None => format!("{quantity} {name}"),
+    }
+}
+
+/// Pick an index into `weights` with probability proportional to each weight,
+/// walking the cumulative total. A weight of 0 is never selected unless every
+/// weight is 0, in which case the choice falls back to uniform.
+fn weighted_index(weights: &[u64], rng: &mut impl Rng) -> usize {
+    let total = weights.iter().sum::<u64>();
+    if total == 0 {
+        return rng.gen_range(0..weights.len());
+    }
+    let mut r = rng.gen_range(0..total);
+    weights
+        .iter()
+        .position(|w| {
+            if r < *w {
+                true
+            } else {
+                r -= *w;
+                false
+            }
+        })
+        .expect("cumulative weight walk always selects an entry")
+}
+
+/// Whether a path names a YAML recipe file. Checks the extension rather than
+/// a whole-path suffix so real filenames like `soup.yaml` actually match.
+fn is_yaml(path: &std::path::Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("yaml") | Some("yml")
+    )
+}
+
+/// The directory remote recipes are cached under. Honors `XDG_CACHE_HOME`,
+/// falling back to `$HOME/.cache` and finally a relative `.cache`.
+fn cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(|| PathBuf::from(".cache"));
+    base.join("meal_randomizer")
+}
+
+/// The on-disk cache path for a recipe URL, keyed by a hash of the URL.
+fn cache_path_for(url: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    cache_dir().join(format!("{:016x}.yaml", hasher.finish()))
+}
+
+/// Load a recipe from a URL, deserializing from the cache when a copy exists
+/// and is younger than `ttl`, otherwise fetching, caching, and deserializing.
+fn load_remote_recipe(url: &str, ttl: Duration) -> Recipe {
+    let path = cache_path_for(url);
+    if let Ok(modified) = fs::metadata(&path).and_then(|meta| meta.modified()) {
+        if modified.elapsed().map(|age| age < ttl).unwrap_or(false) {
+            let reader = BufReader::new(File::open(&path).unwrap());
+            return serde_yaml::from_reader(reader).unwrap();
+        }
+    }
+
+    let body = ureq::get(url).call().unwrap().into_string().unwrap();
+    let recipe = serde_yaml::from_str(&body).unwrap();
+    fs::create_dir_all(cache_dir()).unwrap();
+    fs::write(&path, &body).unwrap();
+    recipe
+}
+
+/// Remove every cached remote recipe.
+fn clear_cache() {
+    let dir = cache_dir();
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Parse a TTL like `24h`, `30m`, `45s`, or `2d` into a [`Duration`].
+fn parse_duration(raw: &str) -> Result<Duration, String> {
+    let raw = raw.trim();
+    let split = raw.find(|c: char| !c.is_ascii_digit()).unwrap_or(raw.len());
+    let (value, unit) = raw.split_at(split);
+    let value = value
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration: {raw}"))?;
+    let seconds = match unit {
+        "s" | "" => value,
+        "m" => value * 60,
+        "h" => value * 60 * 60,
+        "d" => value * 60 * 60 * 24,
+        other => return Err(format!("unknown duration unit: {other}")),
+    };
+    Ok(Duration::from_secs(seconds))
+}
+
+/// Lowercase and trim a list of CLI-supplied ingredient filters.
+fn lowercased(values: &[String]) -> Vec<String> {
+    values.iter().map(|v| v.trim().to_lowercase()).collect()
+}
+
+/// Read a pantry file into a set of lowercased ingredient names, one per line.
+/// Blank lines are ignored; an absent path yields an empty pantry.
+fn read_pantry(path: Option<&PathBuf>) -> HashSet<String> {
+    let Some(path) = path else {
+        return HashSet::new();
+    };
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Print a quantity without a trailing `.0` and without noise decimals.
+fn format_quantity(quantity: f64) -> String {
+    if quantity.fract().abs() < f64::EPSILON {
+        format!("{}", quantity as i64)
+    } else {
+        format!("{quantity:.2}")
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
     }
 }
 
@@ -212,21 +703,214 @@ struct GetRandomRecipes {
     #[clap(short, long, num_args = 1..)]
     ethnicity: Vec<EthnicityFilter>,
 
-    /// The directory containing the recipes to randomize over in YAML format
+    /// The directory containing the recipes to randomize over in YAML format.
+    /// Optional so `--clear-cache` and URL-only runs work without it.
     #[clap(short, long)]
-    recipes_dir: PathBuf,
+    recipes_dir: Option<PathBuf>,
 
     /// The number of recipes to return
     #[clap(short, long, default_value = "3")]
     num_recipes: usize,
+
+    /// Only keep recipes containing at least one of these ingredients.
+    #[clap(long, num_args = 1..)]
+    include_ingredient: Vec<String>,
+
+    /// Drop recipes containing any of these ingredients.
+    #[clap(long, num_args = 1..)]
+    exclude_ingredient: Vec<String>,
+
+    /// A file of on-hand ingredients; prefer recipes that reuse them.
+    #[clap(long)]
+    pantry: Option<PathBuf>,
+
+    /// Fetch recipe YAML from an HTTP(S) URL; may be repeated.
+    #[clap(long)]
+    recipe_url: Vec<String>,
+
+    /// How long cached remote recipes stay fresh (e.g. `24h`, `30m`).
+    #[clap(long, default_value = "24h", value_parser = parse_duration)]
+    cache_ttl: Duration,
+
+    /// Wipe the cached remote recipes and exit.
+    #[clap(long)]
+    clear_cache: bool,
+
+    /// The language to render recipes in, falling back to English.
+    #[clap(long, default_value_t = Lang::Eng)]
+    lang: Lang,
+
+    /// Build an N-day plan that reuses ingredients across its days.
+    #[clap(long)]
+    days: Option<usize>,
 }
 
 fn main() {
     let args = GetRandomRecipes::parse();
+
+    if args.clear_cache {
+        clear_cache();
+        println!("Cleared recipe cache at {}", cache_dir().display());
+        return;
+    }
+
     let recipes = Recipes::from_args(&args);
-    let selected_recipe_paths = recipes.randomly_select_recipes(args.num_recipes);
+    let selected_recipe_paths = match args.days {
+        Some(days) => recipes.plan_days(days),
+        None => recipes.select_recipes(args.num_recipes),
+    };
+
+    println!("Selected recipes:");
+    for (idx, path) in selected_recipe_paths.iter().enumerate() {
+        let Some(recipe) = recipes.inner.get(path) else {
+            continue;
+        };
+        let name = recipe.name.get(args.lang);
+        match args.days {
+            Some(_) => println!("\nDay {}: {name}", idx + 1),
+            None => println!("\n{name}"),
+        }
+        for (i, step) in recipe.steps.get(args.lang).iter().enumerate() {
+            println!("  {}. {step}", i + 1);
+        }
+    }
+
+    println!("\nGrocery list:");
+    for item in recipes.grocery_list(&selected_recipe_paths) {
+        println!("  {item}");
+    }
 
     // TODO - nice PDF grocery list
     // TODO - nice PDF recipe
-    println!("{selected_recipe_paths:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn parses_glued_unit_and_quantity() {
+        let ing = Ingredient::parse("135g plain flour");
+        assert_eq!(ing.quantity, Some(135.0));
+        assert_eq!(ing.unit, Some(Unit::Gram));
+        assert_eq!(ing.name, "plain flour");
+    }
+
+    #[test]
+    fn parses_spaced_unit_and_quantity() {
+        let ing = Ingredient::parse("2 tbsp caster sugar");
+        assert_eq!(ing.quantity, Some(2.0));
+        assert_eq!(ing.unit, Some(Unit::Tablespoon));
+        assert_eq!(ing.name, "caster sugar");
+    }
+
+    #[test]
+    fn parses_count_without_unit() {
+        let ing = Ingredient::parse("1 large egg");
+        assert_eq!(ing.quantity, Some(1.0));
+        assert_eq!(ing.unit, None);
+        assert_eq!(ing.name, "large egg");
+    }
+
+    #[test]
+    fn parses_fraction() {
+        let ing = Ingredient::parse("1/2 cup milk");
+        assert_eq!(ing.quantity, Some(0.5));
+        assert_eq!(ing.unit, Some(Unit::Cup));
+        assert_eq!(ing.name, "milk");
+    }
+
+    #[test]
+    fn parses_countless_pantry_item() {
+        let ing = Ingredient::parse("salt to taste");
+        assert_eq!(ing.quantity, None);
+        assert_eq!(ing.unit, None);
+        assert_eq!(ing.name, "salt to taste");
+    }
+
+    #[test]
+    fn empty_line_is_empty_pantry_item() {
+        let ing = Ingredient::parse("");
+        assert_eq!(ing.quantity, None);
+        assert_eq!(ing.unit, None);
+        assert_eq!(ing.name, "");
+    }
+
+    #[test]
+    fn lone_slash_is_not_a_quantity() {
+        let ing = Ingredient::parse("/ a mystery");
+        assert_eq!(ing.quantity, None);
+        assert_eq!(ing.unit, None);
+        assert_eq!(ing.name, "/ a mystery");
+    }
+
+    #[test]
+    fn glued_non_unit_falls_back_to_name() {
+        let ing = Ingredient::parse("3cloves garlic");
+        assert_eq!(ing.quantity, None);
+        assert_eq!(ing.unit, None);
+        assert_eq!(ing.name, "3cloves garlic");
+    }
+
+    #[test]
+    fn split_numeric_prefix_splits_glued_unit() {
+        assert_eq!(split_numeric_prefix("135g"), ("135", "g"));
+        assert_eq!(split_numeric_prefix("2"), ("2", ""));
+        assert_eq!(split_numeric_prefix("salt"), ("", "salt"));
+    }
+
+    #[test]
+    fn parse_quantity_handles_forms() {
+        assert_eq!(parse_quantity("3"), Some(3.0));
+        assert_eq!(parse_quantity("1.5"), Some(1.5));
+        assert_eq!(parse_quantity("1/4"), Some(0.25));
+        assert_eq!(parse_quantity("1/0"), None);
+        assert_eq!(parse_quantity(""), None);
+        assert_eq!(parse_quantity("/"), None);
+    }
+
+    #[test]
+    fn weighted_index_never_selects_zero_weight() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let weights = [0, 5, 0, 3];
+        for _ in 0..1_000 {
+            let idx = weighted_index(&weights, &mut rng);
+            assert_ne!(weights[idx], 0, "a zero-weight entry must never be chosen");
+        }
+    }
+
+    #[test]
+    fn weighted_index_all_zero_falls_back_to_uniform() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let weights = [0, 0, 0];
+        for _ in 0..100 {
+            let idx = weighted_index(&weights, &mut rng);
+            assert!(idx < weights.len());
+        }
+    }
+
+    #[test]
+    fn weighted_index_single_nonzero_always_wins() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let weights = [0, 0, 9, 0];
+        for _ in 0..100 {
+            assert_eq!(weighted_index(&weights, &mut rng), 2);
+        }
+    }
+
+    #[test]
+    fn parse_duration_accepts_known_units() {
+        assert_eq!(parse_duration("24h"), Ok(Duration::from_secs(24 * 3600)));
+        assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("2d"), Ok(Duration::from_secs(2 * 86400)));
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn parse_duration_rejects_unknown_unit() {
+        assert!(parse_duration("5w").is_err());
+        assert!(parse_duration("abc").is_err());
+    }
 }